@@ -1,8 +1,8 @@
 use std::time::Duration;
 
 use reqwest::{Client, Url};
-use secrecy::SecretString;
-use serde::Deserialize;
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::json;
 use tokio::{
     select,
@@ -14,16 +14,47 @@ use crate::{
     Bridge,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 /// Represents an authenticated device/app interacting with the bridge.
 /// The bridge physically authenticates devices using a button press.
+///
+/// The credentials are generated once via [`Authenticator::request`] and are
+/// meant to be persisted and reused: an [`Authenticator`] is
+/// [`Serialize`]/[`Deserialize`] and can also be rebuilt from stored parts
+/// with [`Authenticator::from_parts`], so the button press is only needed the
+/// first time.
 pub struct Authenticator {
+    #[serde(serialize_with = "expose")]
     username: SecretString,
+    #[serde(serialize_with = "expose")]
     clientkey: SecretString,
 }
 
+/// Serialize a [`SecretString`] by exposing its inner value.
+///
+/// `secrecy` gates `Serialize for Secret<T>` behind `T: SerializableSecret`,
+/// which `String` deliberately does not implement, so the serialize half has to
+/// be provided explicitly for credentials we intend to persist.
+fn expose<S>(secret: &SecretString, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(secret.expose_secret())
+}
+
 impl Authenticator {
+    /// Reconstruct an [`Authenticator`] from a previously stored `username` and
+    /// `clientkey`, skipping the button-press [`request`] flow.
+    ///
+    /// [`request`]: Authenticator::request
+    pub fn from_parts(username: SecretString, clientkey: SecretString) -> Self {
+        Self {
+            username,
+            clientkey,
+        }
+    }
+
     /// The `username` used to authenticate with the [Bridge]
     pub fn username(&self) -> &SecretString {
         &self.username
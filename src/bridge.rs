@@ -51,14 +51,6 @@ impl Bridge {
         self.port
     }
 
-    /*/// Returns the base for the clip API v2: `https://{bridge address}/clip/v2`
-    pub(crate) fn base(&self) -> Url {
-        let mut base = Url::parse("/clip/v2").unwrap();
-        base.set_host(Some(&self.host.to_string())).unwrap();
-        // bridge uses tls with a cert signed by signify / philips hues root ca
-        base.set_scheme("https").unwrap();
-        base
-    }*/
     /// Bridge discovery
     ///
     /// This function tries to discover [`Bridge`]s on the same network.
@@ -95,6 +87,19 @@ impl Bridge {
     /// [Philips Hue's discovery]: <https://discovery.meethue.com/>
     pub async fn discover(client: &Client) -> Result<impl Iterator<Item = Self>, Error> {
         let mut discovered = HashSet::new();
+        // A bridge reachable over both transports shares the same `id`, so we
+        // dedup on it to make sure it only shows up once.
+        let mut ids = HashSet::new();
+
+        #[cfg(feature = "discover_mdns")]
+        {
+            // mdns first, so a bridge found locally wins over the remote entry.
+            for bridge in discover_mdns::discover().await? {
+                if ids.insert(bridge.id.clone()) {
+                    discovered.insert(bridge);
+                }
+            }
+        }
 
         #[cfg(feature = "discover_remote")]
         {
@@ -102,21 +107,64 @@ impl Bridge {
             let endpoint = Url::parse("https://discovery.meethue.com/").unwrap();
             let request = Request::new(Method::GET, endpoint);
 
-            // parse them and add them to the already discovered bridges
-            discovered.extend(
-                client
-                    .execute(request)
-                    .await?
-                    .json::<HashSet<discover_remote::Response>>()
-                    .await?
-                    .into_iter()
-                    .map(Into::into),
-            );
+            // parse them and add the ones we haven't seen over mdns yet
+            for bridge in client
+                .execute(request)
+                .await?
+                .json::<HashSet<discover_remote::Response>>()
+                .await?
+                .into_iter()
+                .map(Bridge::from)
+            {
+                if ids.insert(bridge.id.clone()) {
+                    discovered.insert(bridge);
+                }
+            }
         }
 
-        // TODO: add mDNS
         Ok(discovered.into_iter())
     }
+
+    /// Returns the base for the clip API v2: `https://{bridge address}/clip/v2`
+    pub(crate) fn base(&self) -> Url {
+        // bridge uses tls with a cert signed by signify / philips hues root ca
+        Url::parse(&format!("https://{}/clip/v2", self.host)).unwrap()
+    }
+
+    /// Fetch the bridge's UPnP/description metadata.
+    ///
+    /// This reads the unauthenticated subset of the bridge configuration from
+    /// the `/api/0/config` endpoint. It is a cheap way to confirm a discovered
+    /// [Host] really is a Hue bridge and to inspect the API version before
+    /// reaching for the CLIP v2 layer, without needing an [`Authenticator`].
+    ///
+    /// [`Authenticator`]: crate::Authenticator
+    pub async fn description(&self, client: &Client) -> Result<Description, Error> {
+        let url = Url::parse(&format!("https://{}/api/0/config", self.host)).unwrap();
+        Ok(client.get(url).send().await?.json().await?)
+    }
+}
+
+/// The human-readable metadata a [`Bridge`] reports about itself.
+///
+/// Obtained through [`Bridge::description`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[non_exhaustive]
+pub struct Description {
+    /// The user-facing name of the bridge.
+    pub name: String,
+    /// The software version running on the bridge.
+    #[serde(rename = "swversion")]
+    pub software_version: String,
+    /// The version of the REST API the bridge exposes.
+    #[serde(rename = "apiversion")]
+    pub api_version: String,
+    /// The model id of the bridge hardware.
+    #[serde(rename = "modelid")]
+    pub model_id: String,
+    /// The unique id of the bridge, matching [`Bridge::id`].
+    #[serde(rename = "bridgeid")]
+    pub bridge_id: String,
 }
 
 #[cfg(feature = "discover_remote")]
@@ -155,3 +203,102 @@ mod discover_remote {
         }
     }
 }
+
+#[cfg(feature = "discover_mdns")]
+mod discover_mdns {
+    use std::time::Duration;
+
+    use futures_util::{pin_mut, StreamExt};
+    use mdns::{Error, RecordKind, Response};
+    use tokio::time::{timeout, Instant};
+    use url::Host;
+
+    use crate::Bridge;
+
+    /// The DNS-SD service type the bridge advertises on the local network.
+    const SERVICE_NAME: &str = "_hue._tcp.local";
+
+    /// How long to wait for multicast responses before giving up. Keeping this
+    /// short lets [`Bridge::discover`] fall through to the remote step quickly
+    /// when no bridge answers on the local network.
+    ///
+    /// [`Bridge::discover`]: crate::Bridge::discover
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// How long to keep collecting once a first bridge has answered, so sibling
+    /// bridges on the same round are caught without waiting out the full
+    /// [`TIMEOUT`]. Also doubles as the mDNS re-query interval.
+    const RESPONSE_WAIT: Duration = Duration::from_millis(500);
+
+    /// Browse for bridges advertising [`SERVICE_NAME`].
+    ///
+    /// Every responder that answers within [`TIMEOUT`] is mapped into a
+    /// [`Bridge`]; if no one answers an empty [`Vec`] is returned instead of
+    /// blocking.
+    pub(super) async fn discover() -> Result<Vec<Bridge>, Error> {
+        let stream = mdns::discover::all(SERVICE_NAME, RESPONSE_WAIT)?.listen();
+        pin_mut!(stream);
+
+        let deadline = Instant::now() + TIMEOUT;
+        let mut bridges = Vec::new();
+        loop {
+            // Wait out the whole window while nothing has answered, but once a
+            // bridge is found only linger [`RESPONSE_WAIT`] for its siblings,
+            // so a successful local discovery returns promptly instead of
+            // re-querying until the deadline.
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let wait = if bridges.is_empty() {
+                remaining
+            } else {
+                RESPONSE_WAIT.min(remaining)
+            };
+            if wait.is_zero() {
+                break;
+            }
+
+            match timeout(wait, stream.next()).await {
+                Ok(Some(response)) => {
+                    if let Some(bridge) = from_response(&response?) {
+                        bridges.push(bridge);
+                    }
+                }
+                // the stream ended, or we waited long enough with nothing new
+                Ok(None) | Err(_) => break,
+            }
+        }
+        Ok(bridges)
+    }
+
+    /// Build a [`Bridge`] from the records of a single mdns response.
+    ///
+    /// The host and port come from the A/AAAA and SRV records while the unique
+    /// `id` is read from the `bridgeid` key of the TXT record. A response that
+    /// is missing any of them is ignored.
+    fn from_response(response: &Response) -> Option<Bridge> {
+        let mut host = None;
+        let mut id = None;
+
+        for record in response.records() {
+            match &record.kind {
+                RecordKind::A(addr) => host = Some(Host::Ipv4(*addr)),
+                RecordKind::AAAA(addr) => {
+                    host.get_or_insert(Host::Ipv6(*addr));
+                }
+                RecordKind::TXT(entries) => {
+                    for entry in entries {
+                        if let Some(value) = entry.strip_prefix("bridgeid=") {
+                            id = Some(value.to_owned());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Some(Bridge {
+            host: host?,
+            id: id?,
+            port: response.port()?,
+        })
+    }
+}
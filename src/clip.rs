@@ -0,0 +1,258 @@
+use reqwest::Client;
+use secrecy::ExposeSecret;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    Authenticator, Bridge,
+};
+
+/// The header the CLIP v2 API uses to authenticate a request. It carries the
+/// `username` generated by the [`Authenticator`].
+pub(crate) const APPLICATION_KEY: &str = "hue-application-key";
+
+/// Every CLIP v2 response is wrapped in this envelope: `errors` lists anything
+/// the bridge rejected and `data` holds the requested resources.
+#[derive(Debug, Deserialize)]
+struct Envelope<T> {
+    errors: Vec<ApiError>,
+    data: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiError {
+    description: String,
+}
+
+impl<T> Envelope<T> {
+    /// Turn the envelope into its `data`, surfacing the first API error (if
+    /// any) through [`Error::Api`] instead of silently ignoring it.
+    fn into_data(self) -> Result<Vec<T>> {
+        match self.errors.into_iter().next() {
+            Some(error) => Err(Error::Api(error.description)),
+            None => Ok(self.data),
+        }
+    }
+}
+
+/// Whether a resource is switched on. CLIP v2 nests this under an `on` object.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct On {
+    /// `true` while the resource is switched on.
+    pub on: bool,
+}
+
+/// The brightness of a light as a percentage in `0.0..=100.0`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Dimming {
+    /// Brightness in percent.
+    pub brightness: f32,
+}
+
+/// The white point of a light expressed in [mirek].
+///
+/// [mirek]: <https://en.wikipedia.org/wiki/Mired>
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ColorTemperature {
+    /// Color temperature in mirek (`153..=500`).
+    pub mirek: u16,
+}
+
+/// A point in the [CIE xy] color space.
+///
+/// [CIE xy]: <https://developers.meethue.com/develop/application-design-guidance/color-conversion-formulas-rgb-to-xy-and-back/>
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Xy {
+    /// The x coordinate in `0.0..=1.0`.
+    pub x: f32,
+    /// The y coordinate in `0.0..=1.0`.
+    pub y: f32,
+}
+
+/// The color of a light as a CIE xy coordinate.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Color {
+    /// The color point.
+    pub xy: Xy,
+}
+
+/// The current state of a single light as returned by [`Bridge::get_lights`].
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+pub struct LightState {
+    /// The unique id of the light resource.
+    pub id: String,
+    /// Whether the light is switched on.
+    pub on: On,
+    /// The brightness, if the light is dimmable.
+    #[serde(default)]
+    pub dimming: Option<Dimming>,
+    /// The white point, if the light supports tunable white.
+    #[serde(default)]
+    pub color_temperature: Option<ColorTemperature>,
+    /// The color, if the light supports color.
+    #[serde(default)]
+    pub color: Option<Color>,
+}
+
+/// A partial update sent to [`Bridge::set_light`]. Only the fields that are
+/// `Some` are written; everything else is left untouched by the bridge.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LightUpdate {
+    /// Switch the light on or off.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on: Option<On>,
+    /// Set the brightness.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimming: Option<Dimming>,
+    /// Set the white point.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_temperature: Option<ColorTemperature>,
+    /// Set the color.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+}
+
+/// A grouped light, i.e. the aggregated on/brightness state of a room or zone.
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+pub struct GroupedLight {
+    /// The unique id of the grouped-light resource.
+    pub id: String,
+    /// The aggregated on/off state.
+    pub on: On,
+    /// The aggregated brightness, if available.
+    #[serde(default)]
+    pub dimming: Option<Dimming>,
+}
+
+/// A scene: a named, recallable set of light states.
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+pub struct Scene {
+    /// The unique id of the scene resource.
+    pub id: String,
+    /// The human-readable metadata of the scene.
+    pub metadata: SceneMetadata,
+}
+
+/// The metadata attached to a [`Scene`].
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+pub struct SceneMetadata {
+    /// The user-facing name of the scene.
+    pub name: String,
+}
+
+impl Bridge {
+    /// Read every light known to the bridge.
+    pub async fn get_lights(
+        &self,
+        auth: &Authenticator,
+        client: &Client,
+    ) -> Result<Vec<LightState>> {
+        self.get(auth, client, "resource/light").await
+    }
+
+    /// Read every grouped light (rooms and zones) known to the bridge.
+    pub async fn get_groups(
+        &self,
+        auth: &Authenticator,
+        client: &Client,
+    ) -> Result<Vec<GroupedLight>> {
+        self.get(auth, client, "resource/grouped_light").await
+    }
+
+    /// Read every scene known to the bridge.
+    pub async fn get_scenes(&self, auth: &Authenticator, client: &Client) -> Result<Vec<Scene>> {
+        self.get(auth, client, "resource/scene").await
+    }
+
+    /// Apply an `update` to the light with the given `id`.
+    pub async fn set_light(
+        &self,
+        auth: &Authenticator,
+        client: &Client,
+        id: &str,
+        update: &LightUpdate,
+    ) -> Result<()> {
+        self.put(auth, client, &format!("resource/light/{id}"), update)
+            .await
+    }
+
+    /// `PUT` a body to a resource `path`, surfacing a rejected write through
+    /// [`Error::Api`].
+    pub(crate) async fn put<T: Serialize>(
+        &self,
+        auth: &Authenticator,
+        client: &Client,
+        path: &str,
+        body: &T,
+    ) -> Result<()> {
+        client
+            .put(self.resource(path))
+            .header(APPLICATION_KEY, auth.username().expose_secret())
+            .json(body)
+            .send()
+            .await?
+            // the bridge echoes the changed references through the same
+            // envelope, so a rejected write still surfaces as `Error::Api`.
+            .json::<Envelope<serde::de::IgnoredAny>>()
+            .await?
+            .into_data()
+            .map(drop)
+    }
+
+    /// `GET` a collection of resources from the CLIP v2 API and unwrap the
+    /// `{ errors, data }` envelope.
+    async fn get<T: DeserializeOwned>(
+        &self,
+        auth: &Authenticator,
+        client: &Client,
+        path: &str,
+    ) -> Result<Vec<T>> {
+        client
+            .get(self.resource(path))
+            .header(APPLICATION_KEY, auth.username().expose_secret())
+            .send()
+            .await?
+            .json::<Envelope<T>>()
+            .await?
+            .into_data()
+    }
+
+    /// Build the URL for a resource `path` relative to [`Bridge::base`].
+    pub(crate) fn resource(&self, path: &str) -> reqwest::Url {
+        reqwest::Url::parse(&format!("{}/{path}", self.base())).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Envelope, LightState};
+    use crate::error::Error;
+
+    #[test]
+    fn envelope_surfaces_api_error() {
+        let body = r#"{"errors":[{"description":"device (light) has no reference"}],"data":[]}"#;
+        let envelope: Envelope<LightState> = serde_json::from_str(body).unwrap();
+        match envelope.into_data() {
+            Err(Error::Api(description)) => {
+                assert_eq!(description, "device (light) has no reference");
+            }
+            other => panic!("expected an api error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn envelope_returns_data_without_errors() {
+        let body = r#"{"errors":[],"data":[{"id":"abc","on":{"on":true}}]}"#;
+        let lights = serde_json::from_str::<Envelope<LightState>>(body)
+            .unwrap()
+            .into_data()
+            .unwrap();
+        assert_eq!(lights.len(), 1);
+        assert_eq!(lights[0].id, "abc");
+        assert!(lights[0].on.on);
+    }
+}
@@ -0,0 +1,266 @@
+use std::{
+    io::{Read, Write},
+    net::UdpSocket,
+    time::Duration,
+};
+
+use openssl::ssl::{Ssl, SslContext, SslMethod, SslStream};
+use reqwest::Client;
+use secrecy::ExposeSecret;
+use serde_json::json;
+
+use crate::{error::Result, Authenticator, Bridge};
+
+/// The UDP port the bridge listens on for the Entertainment stream. Note that
+/// this differs from the REST API port.
+const STREAM_PORT: u16 = 2100;
+
+/// The magic prefix every Entertainment message starts with.
+const MAGIC: &[u8; 9] = b"HueStream";
+
+/// Protocol version `2.0`, as major/minor bytes.
+const VERSION: [u8; 2] = [0x02, 0x00];
+
+/// The cipher suite the bridge expects for the PSK DTLS handshake.
+const CIPHER: &str = "PSK-AES128-GCM-SHA256";
+
+/// How long a single blocking `recv` during the handshake waits before giving
+/// up, so a lost datagram times out instead of hanging forever.
+const HANDSHAKE_READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How many times the handshake is retried after a read timeout before the
+/// whole attempt is surfaced as an error. Each retry lets the bridge's
+/// retransmitted flight complete the handshake.
+const HANDSHAKE_RETRIES: u8 = 10;
+
+/// How colors in a [frame] are encoded.
+///
+/// [frame]: EntertainmentStream::send
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ColorSpace {
+    /// Three 16-bit red/green/blue components.
+    Rgb,
+    /// A CIE xy coordinate plus brightness, all 16-bit.
+    Xy,
+}
+
+impl ColorSpace {
+    /// The wire flag for this color space.
+    fn flag(self) -> u8 {
+        match self {
+            ColorSpace::Rgb => 0x00,
+            ColorSpace::Xy => 0x01,
+        }
+    }
+}
+
+/// A single light channel of an [`EntertainmentStream`] frame: the channel id
+/// followed by its three color components in the active [`ColorSpace`].
+#[derive(Debug, Clone, Copy)]
+pub struct Channel {
+    /// The channel id as configured in the entertainment configuration.
+    pub id: u8,
+    /// The three 16-bit color components.
+    pub color: [u16; 3],
+}
+
+/// A live DTLS connection to the bridge's Entertainment API.
+///
+/// Obtain one with [`Bridge::entertainment`], then push color frames with
+/// [`EntertainmentStream::send`]. Frames may be sent up to ~50 times per
+/// second for low-latency effects.
+#[derive(Debug)]
+pub struct EntertainmentStream {
+    stream: SslStream<UdpTransport>,
+    configuration_id: String,
+    sequence: u8,
+}
+
+impl EntertainmentStream {
+    /// Push a single frame of `channels` encoded in `color_space`.
+    pub fn send(&mut self, color_space: ColorSpace, channels: &[Channel]) -> Result<()> {
+        let frame = self.frame(color_space, channels);
+        self.stream.write_all(&frame)?;
+        Ok(())
+    }
+
+    /// Serialize a frame: the fixed header followed by the per-channel entries.
+    fn frame(&mut self, color_space: ColorSpace, channels: &[Channel]) -> Vec<u8> {
+        let buf = encode_frame(self.sequence, &self.configuration_id, color_space, channels);
+        // the sequence id is informational; the bridge ignores it but it wraps
+        self.sequence = self.sequence.wrapping_add(1);
+        buf
+    }
+}
+
+/// Encode the on-the-wire bytes of a frame: the fixed header followed by the
+/// per-channel entries. Kept free of `self` so the exact byte layout can be
+/// exercised directly.
+fn encode_frame(
+    sequence: u8,
+    configuration_id: &str,
+    color_space: ColorSpace,
+    channels: &[Channel],
+) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(52 + channels.len() * 7);
+    buf.extend_from_slice(MAGIC);
+    buf.extend_from_slice(&VERSION);
+    buf.push(sequence);
+    buf.extend_from_slice(&[0x00, 0x00]); // reserved
+    buf.push(color_space.flag());
+    buf.push(0x00); // reserved
+    buf.extend_from_slice(configuration_id.as_bytes());
+    for channel in channels {
+        buf.push(channel.id);
+        for component in channel.color {
+            buf.extend_from_slice(&component.to_be_bytes());
+        }
+    }
+    buf
+}
+
+impl Bridge {
+    /// Start streaming on an entertainment configuration and open the DTLS
+    /// channel used to push color frames.
+    ///
+    /// This first tells the bridge to hand the entertainment configuration
+    /// `configuration_id` over to streaming mode via the CLIP v2 REST layer,
+    /// then performs the DTLS handshake on [`STREAM_PORT`] using a pre-shared
+    /// key: the identity is the authenticator's `username` and the key is the
+    /// hex-decoded `clientkey`.
+    pub async fn entertainment(
+        &self,
+        auth: &Authenticator,
+        client: &Client,
+        configuration_id: &str,
+    ) -> Result<EntertainmentStream> {
+        self.put(
+            auth,
+            client,
+            &format!("resource/entertainment_configuration/{configuration_id}"),
+            &json!({ "action": "start" }),
+        )
+        .await?;
+
+        let identity = auth.username().expose_secret().as_bytes().to_vec();
+        let psk = hex::decode(auth.clientkey().expose_secret())?;
+        let host = self.host().to_string();
+
+        // Binding the socket and, above all, the DTLS handshake are blocking
+        // and can stall for seconds (every lost datagram costs a full read
+        // timeout before the retry below), so run them off the runtime worker.
+        let stream =
+            tokio::task::spawn_blocking(move || -> Result<SslStream<UdpTransport>> {
+                let mut builder = SslContext::builder(SslMethod::dtls())?;
+                builder.set_cipher_list(CIPHER)?;
+                builder.set_psk_client_callback(move |_, _, identity_buf, psk_buf| {
+                    // openssl reads the identity as a NUL-terminated string, so
+                    // the identity plus that byte has to fit; bail out instead
+                    // of indexing past the end.
+                    if identity.len() >= identity_buf.len() || psk.len() > psk_buf.len() {
+                        return Ok(0);
+                    }
+                    identity_buf[..identity.len()].copy_from_slice(&identity);
+                    identity_buf[identity.len()] = 0;
+                    psk_buf[..psk.len()].copy_from_slice(&psk);
+                    Ok(psk.len())
+                });
+
+                let socket = UdpSocket::bind("0.0.0.0:0")?;
+                socket.connect((host, STREAM_PORT))?;
+                // Without a read timeout a lost datagram would block `recv`
+                // forever and leak this thread; bounding it lets the DTLS
+                // retransmission recover and turns a dead link into an error.
+                socket.set_read_timeout(Some(HANDSHAKE_READ_TIMEOUT))?;
+
+                let ssl = Ssl::new(&builder.build())?;
+                let mut stream = SslStream::new(ssl, UdpTransport(socket))?;
+
+                let mut retries = HANDSHAKE_RETRIES;
+                loop {
+                    match stream.connect() {
+                        Ok(()) => break Ok(stream),
+                        // a timed-out read just means a flight was lost; retry
+                        // so the retransmission can finish the handshake
+                        Err(error) if is_timeout(&error) && retries > 0 => retries -= 1,
+                        Err(error) => break Err(error.into()),
+                    }
+                }
+            })
+            .await??;
+
+        Ok(EntertainmentStream {
+            stream,
+            configuration_id: configuration_id.to_owned(),
+            sequence: 0,
+        })
+    }
+}
+
+/// Whether a handshake error is the blocking socket hitting its read timeout,
+/// which is recoverable by retrying, rather than a real handshake failure.
+fn is_timeout(error: &openssl::ssl::Error) -> bool {
+    error.io_error().is_some_and(|io| {
+        matches!(
+            io.kind(),
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+        )
+    })
+}
+
+/// Adapts a connected [`UdpSocket`] to the [`Read`]/[`Write`] interface that
+/// [`SslStream`] drives the DTLS handshake and records over.
+#[derive(Debug)]
+struct UdpTransport(UdpSocket);
+
+impl Read for UdpTransport {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.recv(buf)
+    }
+}
+
+impl Write for UdpTransport {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.send(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_frame, Channel, ColorSpace, MAGIC, VERSION};
+
+    #[test]
+    fn frame_header_layout() {
+        let channels = [Channel {
+            id: 7,
+            color: [0x0102, 0x0304, 0x0506],
+        }];
+        let frame = encode_frame(0x2a, "abc", ColorSpace::Xy, &channels);
+
+        // magic + version + sequence + reserved + colorspace + reserved
+        assert_eq!(&frame[..9], MAGIC);
+        assert_eq!(&frame[9..11], &VERSION);
+        assert_eq!(frame[11], 0x2a);
+        assert_eq!(&frame[12..14], &[0x00, 0x00]);
+        assert_eq!(frame[14], ColorSpace::Xy.flag());
+        assert_eq!(frame[15], 0x00);
+        assert_eq!(&frame[16..19], b"abc");
+
+        // the single channel: id followed by three big-endian components
+        assert_eq!(
+            &frame[19..],
+            &[7, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+        );
+    }
+
+    #[test]
+    fn rgb_and_xy_flags_differ() {
+        assert_eq!(ColorSpace::Rgb.flag(), 0x00);
+        assert_eq!(ColorSpace::Xy.flag(), 0x01);
+    }
+}
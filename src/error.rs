@@ -12,6 +12,44 @@ pub enum Error {
     #[error(transparent)]
     /// Errors that may happen during authentication
     Authentication(#[from] AuthenticationError),
+    #[cfg(feature = "discover_mdns")]
+    #[error(transparent)]
+    /// An error that occurred while browsing for bridges over mDNS
+    Mdns(#[from] mdns::Error),
+    #[error("api returned error: {0}")]
+    /// An error reported by the bridge in the `errors` field of a CLIP v2
+    /// response.
+    Api(String),
+    #[error(transparent)]
+    /// A response from the bridge could not be (de)serialized
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    /// An error on the Server-Sent Events stream returned by
+    /// [`crate::Bridge::events`]
+    EventStream(#[from] reqwest_eventsource::Error),
+    #[error(transparent)]
+    /// The request backing the event stream could not be cloned for a reconnect
+    EventRequest(#[from] reqwest_eventsource::CannotCloneRequestError),
+    #[cfg(feature = "entertainment")]
+    #[error(transparent)]
+    /// An I/O error on the Entertainment DTLS socket
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "entertainment")]
+    #[error(transparent)]
+    /// An error while configuring the Entertainment DTLS context
+    Dtls(#[from] openssl::error::ErrorStack),
+    #[cfg(feature = "entertainment")]
+    #[error(transparent)]
+    /// An error during the Entertainment DTLS handshake
+    Handshake(#[from] openssl::ssl::Error),
+    #[cfg(feature = "entertainment")]
+    #[error("clientkey is not valid hex: {0}")]
+    /// The `clientkey` could not be hex-decoded into the Entertainment PSK
+    Hex(#[from] hex::FromHexError),
+    #[cfg(feature = "entertainment")]
+    #[error(transparent)]
+    /// The blocking task running the Entertainment DTLS handshake failed to join
+    Join(#[from] tokio::task::JoinError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,151 @@
+use async_stream::stream;
+use futures_util::{Stream, StreamExt};
+use reqwest::{header::ACCEPT, Client};
+use reqwest_eventsource::{Event as SseEvent, EventSource};
+use secrecy::ExposeSecret;
+use serde::Deserialize;
+
+use crate::{
+    clip::APPLICATION_KEY,
+    error::{Error, Result},
+    Authenticator, Bridge,
+};
+
+/// A change pushed by the bridge over the event stream.
+///
+/// Each message on the stream is a batch of these, tagged with the kind of
+/// change and the resources it affects.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Event {
+    /// One or more resources changed state.
+    Update {
+        /// The resources in their new state.
+        data: Vec<Resource>,
+    },
+    /// One or more resources were added.
+    Add {
+        /// The resources that appeared.
+        data: Vec<Resource>,
+    },
+    /// One or more resources were removed.
+    Delete {
+        /// The resources that were removed.
+        data: Vec<Resource>,
+    },
+}
+
+/// A single resource carried by an [`Event`].
+///
+/// Only the `id` and `type` are promoted to fields; the remaining, resource
+/// specific payload is left as raw JSON so every resource type the bridge can
+/// emit is representable.
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+pub struct Resource {
+    /// The unique id of the changed resource.
+    pub id: String,
+    /// The resource type, e.g. `light`, `grouped_light` or `motion`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The remaining, resource specific fields.
+    #[serde(flatten)]
+    pub data: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Bridge {
+    /// Subscribe to the bridge's [Server-Sent Events] stream.
+    ///
+    /// The returned [`Stream`] yields an [`Event`] for every change the bridge
+    /// pushes. This is the intended way to react to state changes: the bridge
+    /// keeps the connection open indefinitely, so polling the REST layer is
+    /// unnecessary. Dropped connections are reconnected automatically: the
+    /// failure is yielded as an `Err` item and the stream then continues on
+    /// the new connection.
+    ///
+    /// [Server-Sent Events]: <https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events>
+    pub fn events(
+        &self,
+        auth: &Authenticator,
+        client: &Client,
+    ) -> impl Stream<Item = Result<Event>> {
+        // The event stream sits at the top level, not under the `/clip/v2`
+        // resource tree, so build the URL straight from the host like
+        // `description()` does rather than through `resource()`.
+        let url = reqwest::Url::parse(&format!(
+            "https://{}/eventstream/clip/v2",
+            self.host()
+        ))
+        .unwrap();
+        let request = client
+            .get(url)
+            .header(APPLICATION_KEY, auth.username().expose_secret())
+            .header(ACCEPT, "text/event-stream");
+
+        stream! {
+            // `EventSource` resumes with `Last-Event-ID` and retries failures
+            // on its own; each failure is still yielded as an `Err` item.
+            let mut source = match EventSource::new(request) {
+                Ok(source) => source,
+                Err(error) => {
+                    yield Err(Error::from(error));
+                    return;
+                }
+            };
+            while let Some(event) = source.next().await {
+                match event {
+                    // the `Open` event just signals (re)connection
+                    Ok(SseEvent::Open) => continue,
+                    Ok(SseEvent::Message(message)) => {
+                        match serde_json::from_str::<Vec<Event>>(&message.data) {
+                            Ok(events) => {
+                                for event in events {
+                                    yield Ok(event);
+                                }
+                            }
+                            // a message that is not a batch of events ends the stream
+                            Err(error) => {
+                                yield Err(Error::from(error));
+                                return;
+                            }
+                        }
+                    }
+                    // A dropped connection surfaces as an error while
+                    // `EventSource` has already scheduled a reconnect, so keep
+                    // polling: the next poll reconnects and re-emits `Open`. The
+                    // stream ends on its own by yielding `None` once the retry
+                    // policy gives up.
+                    Err(error) => yield Err(Error::from(error)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Event;
+
+    #[test]
+    fn deserialize_update_event() {
+        let body = r#"[{"type":"update","data":[{"id":"abc","type":"light","on":{"on":true}}]}]"#;
+        let events: Vec<Event> = serde_json::from_str(body).unwrap();
+        match &events[0] {
+            Event::Update { data } => {
+                assert_eq!(data[0].id, "abc");
+                assert_eq!(data[0].kind, "light");
+                // the resource-specific payload is kept as raw JSON
+                assert!(data[0].data.contains_key("on"));
+            }
+            other => panic!("expected an update event, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn deserialize_delete_event() {
+        let body = r#"[{"type":"delete","data":[{"id":"xyz","type":"grouped_light"}]}]"#;
+        let events: Vec<Event> = serde_json::from_str(body).unwrap();
+        assert!(matches!(events[0], Event::Delete { .. }));
+    }
+}
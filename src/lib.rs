@@ -4,14 +4,25 @@
 
 mod authentication;
 mod bridge;
+mod clip;
+#[cfg(feature = "entertainment")]
+mod entertainment;
 mod error;
+mod events;
 
 #[doc(inline)]
 pub use authentication::Authenticator;
 #[doc(inline)]
-pub use bridge::Bridge;
+pub use bridge::{Bridge, Description};
+#[doc(inline)]
+pub use clip::*;
+#[cfg(feature = "entertainment")]
+#[doc(inline)]
+pub use entertainment::{Channel, ColorSpace, EntertainmentStream};
 #[doc(inline)]
 pub use error::*;
+#[doc(inline)]
+pub use events::{Event, Resource};
 
 /// The hue bridge uses https with certificates signed by this CA.
 /// See <https://developers.meethue.com/develop/application-design-guidance/using-https/>